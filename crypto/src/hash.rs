@@ -1,12 +1,14 @@
 #[cfg(not(feature = "std"))]
 use alloc::{alloc::alloc, format, string::String, vec, vec::Vec};
-use core::{hash, marker::PhantomData, num::NonZeroU8};
+use core::{hash, marker::PhantomData, str::FromStr};
 
 use derive_more::{DebugCustom, Deref, DerefMut, Display};
 use iroha_schema::prelude::*;
 use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "std")]
+use sha2::{Digest as _, Sha256};
+#[cfg(feature = "std")]
 use ursa::blake2::{
     digest::{Update, VariableOutput},
     VarBlake2b,
@@ -14,66 +16,243 @@ use ursa::blake2::{
 
 use crate::ffi;
 
+/// Discriminant identifying the algorithm that produced a [`Hash`]'s digest.
+///
+/// Carrying the algorithm alongside the digest (rather than hard-wiring a
+/// single one) mirrors `gix_hash::Kind`: it lets Iroha entities migrate hash
+/// functions without forking the wire format.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Encode, Decode, Serialize, Deserialize, IntoSchema,
+)]
+#[repr(u8)]
+pub enum Kind {
+    /// 256-bit (32-byte) blake2b digest. The only algorithm this crate
+    /// produced before [`Kind`] existed, and still the default.
+    Blake2b256 = 0,
+    /// 512-bit (64-byte) blake2b digest.
+    Blake2b512 = 1,
+    /// 256-bit (32-byte) sha256 digest.
+    Sha256 = 2,
+}
+
+impl Default for Kind {
+    fn default() -> Self {
+        Self::Blake2b256
+    }
+}
+
+impl Kind {
+    /// Length, in bytes, of a digest produced by this [`Kind`].
+    pub const fn digest_len(self) -> usize {
+        match self {
+            Self::Blake2b256 | Self::Sha256 => 32,
+            Self::Blake2b512 => 64,
+        }
+    }
+}
+
+/// Error returned by [`Kind`]'s [`TryFrom<u8>`] impl when the byte does not
+/// name a recognised hash algorithm.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+#[display(fmt = "{_0} is not a recognised hash algorithm discriminant")]
+pub struct UnknownKind(u8);
+
+impl TryFrom<u8> for Kind {
+    type Error = UnknownKind;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Blake2b256),
+            1 => Ok(Self::Blake2b512),
+            2 => Ok(Self::Sha256),
+            _ => Err(UnknownKind(value)),
+        }
+    }
+}
+
 ffi::ffi_item! {
-    /// Hash of Iroha entities. Currently supports only blake2b-32.
-    /// The least significant bit of hash is set to 1.
-    #[derive(
-        Clone,
-        Copy,
-        Display,
-        DebugCustom,
-        Hash,
-        Eq,
-        PartialEq,
-        Ord,
-        PartialOrd,
-        IntoSchema,
-    )]
-    #[display(fmt = "{}", "hex::encode(self.as_ref())")]
-    #[debug(fmt = "{}", "hex::encode(self.as_ref())")]
+    /// Hash of Iroha entities, tagged with the [`Kind`] of algorithm that
+    /// produced the digest. Before [`Kind`] was introduced this was
+    /// hard-wired to 32-byte blake2b, which remains [`Kind::default`] so
+    /// existing hashes keep their meaning.
+    /// The least significant bit of the digest is set to 1.
+    ///
+    /// `bytes` is always [`MAX_LENGTH`](Self::MAX_LENGTH) long regardless of
+    /// `kind`, with any bytes past `kind.digest_len()` left as unspecified
+    /// padding (zeroed by every in-crate constructor, but not guaranteed to
+    /// be by values built across the FFI boundary) — so `Eq`/`Ord`/`Hash`
+    /// are implemented by hand below to compare/hash only `kind` and
+    /// [`as_bytes`](Self::as_bytes), never the padding.
+    ///
+    /// `Display`/`Debug` render as hex of the [`Kind`] discriminant followed
+    /// by the significant digest bytes, i.e. the same tag-then-digest layout
+    /// [`Encode`] writes to the wire: without the tag, a [`Kind::Sha256`]
+    /// hash and a [`Kind::Blake2b256`] hash are both 32 bytes and so are
+    /// indistinguishable by length alone, which [`Hash::from_hex`] must be
+    /// able to invert unambiguously.
+    #[derive(Clone, Copy, DebugCustom, Display, IntoSchema)]
+    #[display(fmt = "{:02x}{}", "self.kind as u8", "hex::encode(self.as_bytes())")]
+    #[debug(fmt = "{:02x}{}", "self.kind as u8", "hex::encode(self.as_bytes())")]
     #[repr(C)]
     pub struct Hash {
-        more_significant_bits: [u8; Self::LENGTH - 1],
-        least_significant_byte: NonZeroU8,
+        kind: Kind,
+        bytes: [u8; Self::MAX_LENGTH],
+    }
+}
+
+impl PartialEq for Hash {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind && self.as_bytes() == other.as_bytes()
+    }
+}
+impl Eq for Hash {}
+
+impl PartialOrd for Hash {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Hash {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.kind
+            .cmp(&other.kind)
+            .then_with(|| self.as_bytes().cmp(other.as_bytes()))
+    }
+}
+
+impl hash::Hash for Hash {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.kind.hash(state);
+        self.as_bytes().hash(state);
     }
 }
 
 // NOTE: Hash is FFI serialized as an array (a pointer in a function call, by value when part of a struct)
-iroha_ffi::ffi_type! {unsafe impl Transparent for Hash[[u8; Hash::LENGTH]] validated with {Hash::is_lsb_1} }
+iroha_ffi::ffi_type! {unsafe impl Transparent for Hash[[u8; 1 + Hash::MAX_LENGTH]] validated with {Hash::is_valid_repr} }
 
 impl iroha_ffi::option::Niche for Hash {
-    // NOTE: Any value that has lsb=0 is a niche value
-    const NICHE_VALUE: Self::ReprC = [0; Hash::LENGTH];
+    // NOTE: discriminant `0xFF` never names a valid `Kind`, so this value can
+    // never be produced by a real `Hash` and is safe to use as the niche
+    // sentinel.
+    const NICHE_VALUE: Self::ReprC = {
+        let mut value = [0; 1 + Hash::MAX_LENGTH];
+        value[0] = 0xFF;
+        value
+    };
 }
 
 impl Hash {
-    /// Length of hash
+    /// Length of a [`Kind::default`] digest, and the length every digest had
+    /// before [`Kind`] was introduced.
     pub const LENGTH: usize = 32;
 
-    /// Wrap the given bytes; they must be prehashed with `VarBlake2b`
+    /// Length of the largest digest any supported [`Kind`] can produce.
+    pub const MAX_LENGTH: usize = 64;
+
+    /// Wrap the given bytes as a [`Kind::default`] digest; they must be
+    /// prehashed with `VarBlake2b`.
     pub fn prehashed(mut hash: [u8; Self::LENGTH]) -> Self {
         hash[Self::LENGTH - 1] |= 1;
-        #[allow(unsafe_code)]
-        // SAFETY:
-        // - any `u8` value after bitwise or with 1 will be at least 1
-        // - `Hash` and `[u8; Hash::LENGTH]` have the same memory layout
-        unsafe {
-            core::mem::transmute(hash)
-        }
+        Self::with_prehashed_kind(Kind::default(), &hash)
     }
 
-    /// Hash the given bytes.
+    /// Wrap the given bytes as a digest of the given `kind`. The bytes must
+    /// already be prehashed with the algorithm `kind` names, and must be
+    /// exactly [`Kind::digest_len`] bytes long.
+    ///
+    /// # Panics
+    /// If `digest.len() != kind.digest_len()`.
+    pub fn with_prehashed_kind(kind: Kind, digest: &[u8]) -> Self {
+        assert_eq!(
+            digest.len(),
+            kind.digest_len(),
+            "digest length does not match kind"
+        );
+        let mut bytes = [0_u8; Self::MAX_LENGTH];
+        bytes[..digest.len()].copy_from_slice(digest);
+        bytes[digest.len() - 1] |= 1;
+        Self { kind, bytes }
+    }
+
+    /// Decode a legacy, pre-[`Kind`] SCALE payload: a bare `Kind::default()`
+    /// digest with no discriminant byte, the only format ever written to the
+    /// wire before this type gained [`Kind`].
+    ///
+    /// This is an explicit migration-boundary entry point, not something
+    /// [`Decode::decode`] falls back to automatically: a tag byte can't be
+    /// told apart from a legacy digest's first byte by inspection alone (see
+    /// [`Decode for Hash`](#impl-Decode-for-Hash)), so callers that know
+    /// they are reading pre-migration storage must opt into this explicitly.
+    pub fn decode_legacy<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        let digest = <[u8; Self::LENGTH]>::decode(input)?;
+        Self::is_lsb_1(&digest)
+            .then(|| Self::prehashed(digest))
+            .ok_or_else(|| "expect least significant bit of hash to be 1".into())
+    }
+
+    /// Deserialize a legacy, pre-[`Kind`] serde payload: a bare
+    /// `Kind::default()` digest serialized through the derived
+    /// `[u8; Self::LENGTH]` impl, the only format ever produced before this
+    /// type gained [`Kind`].
+    ///
+    /// Like [`Hash::decode_legacy`], this is an explicit migration-boundary
+    /// entry point, not something [`Deserialize::deserialize`] falls back to
+    /// automatically: callers that know they are reading pre-migration data
+    /// must opt into this explicitly.
+    pub fn deserialize_legacy<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        use serde::de::Error as _;
+
+        let digest = <[u8; Self::LENGTH]>::deserialize(deserializer)?;
+        Self::is_lsb_1(&digest)
+            .then(|| Self::prehashed(digest))
+            .ok_or_else(|| D::Error::custom("expect least significant bit of hash to be 1"))
+    }
+
+    /// Hash the given bytes using [`Kind::default`] (32-byte blake2b).
     #[cfg(feature = "std")]
-    #[allow(clippy::expect_used)]
     #[must_use]
     pub fn new(bytes: impl AsRef<[u8]>) -> Self {
-        let vec_hash = VarBlake2b::new(Self::LENGTH)
-            .expect("Failed to initialize variable size hash")
-            .chain(bytes)
-            .finalize_boxed();
-        let mut hash = [0; Self::LENGTH];
-        hash.copy_from_slice(&vec_hash);
-        Hash::prehashed(hash)
+        Self::with_kind(Kind::default(), bytes)
+    }
+
+    /// Hash the given bytes, dispatching to the digest implementation that
+    /// `kind` names.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn with_kind(kind: Kind, bytes: impl AsRef<[u8]>) -> Self {
+        let mut hasher = Self::hasher_with_kind(kind);
+        hasher.update(bytes);
+        hasher.finalize()
+    }
+
+    /// Begin an incremental digest using [`Kind::default`] (32-byte blake2b).
+    ///
+    /// Prefer this over [`Hash::new`] when the payload (e.g. an encoded
+    /// block or transaction) would otherwise have to be materialized into a
+    /// single contiguous buffer just to be hashed.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn hasher() -> HashBuilder {
+        Self::hasher_with_kind(Kind::default())
+    }
+
+    /// Begin an incremental digest using the given algorithm `kind`.
+    #[cfg(feature = "std")]
+    #[allow(clippy::expect_used)]
+    #[must_use]
+    pub fn hasher_with_kind(kind: Kind) -> HashBuilder {
+        let inner = match kind {
+            Kind::Blake2b256 | Kind::Blake2b512 => HashBuilderInner::Blake2b(
+                VarBlake2b::new(kind.digest_len())
+                    .expect("Failed to initialize variable size hash"),
+            ),
+            Kind::Sha256 => HashBuilderInner::Sha256(Sha256::default()),
+        };
+        HashBuilder { kind, inner }
     }
 
     /// Adds type information to the hash. Be careful about using this function
@@ -84,31 +263,297 @@ impl Hash {
         HashOf(self, PhantomData)
     }
 
-    /// Check if least significant bit of `[u8; Hash::LENGTH]` is 1
-    fn is_lsb_1(hash: &[u8; Self::LENGTH]) -> bool {
-        hash[Self::LENGTH - 1] & 1 == 1
+    /// The hash algorithm that produced this digest.
+    #[must_use]
+    pub const fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// The significant digest bytes, i.e. excluding the unused trailing
+    /// padding that shorter-than-[`MAX_LENGTH`](Self::MAX_LENGTH) kinds leave behind.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.kind.digest_len()]
+    }
+
+    /// Check if the least significant bit of the last byte of `digest` is 1.
+    fn is_lsb_1(digest: &[u8]) -> bool {
+        digest.last().map_or(false, |byte| byte & 1 == 1)
+    }
+
+    /// Check that `repr` starts with a recognised [`Kind`] discriminant and
+    /// that the least significant bit of the resulting digest is 1.
+    fn is_valid_repr(repr: &[u8; 1 + Self::MAX_LENGTH]) -> bool {
+        match Kind::try_from(repr[0]) {
+            Ok(kind) => Self::is_lsb_1(&repr[1..=kind.digest_len()]),
+            Err(_) => false,
+        }
+    }
+
+    /// Parse a digest of any [`Kind`] from its hexadecimal textual form, i.e.
+    /// the inverse of [`Hash`]'s `Display`/`Debug` output: a leading [`Kind`]
+    /// discriminant byte followed by `kind.digest_len()` digest bytes, all
+    /// hex-encoded.
+    ///
+    /// The tag is load-bearing, not cosmetic: a [`Kind::Sha256`] digest and a
+    /// [`Kind::Blake2b256`] digest are both 32 bytes, so without it two
+    /// different-algorithm hashes could parse to the same length-64 hex
+    /// string and there would be no way to tell which [`Kind`] was meant.
+    pub fn from_hex(hex: &str) -> Result<Self, ParseError> {
+        let chars: Vec<char> = hex.chars().collect();
+        if chars.len() < 2 {
+            return Err(ParseError::InvalidHexLength {
+                expected: 2,
+                actual: chars.len(),
+            });
+        }
+
+        let kind_hi = chars[0]
+            .to_digit(16)
+            .ok_or(ParseError::InvalidHexCharacter(chars[0]))?;
+        let kind_lo = chars[1]
+            .to_digit(16)
+            .ok_or(ParseError::InvalidHexCharacter(chars[1]))?;
+        let kind = Kind::try_from(((kind_hi << 4) | kind_lo) as u8).map_err(ParseError::UnknownKind)?;
+
+        let digest_chars = &chars[2..];
+        let expected = 2 * kind.digest_len();
+        if digest_chars.len() != expected {
+            return Err(ParseError::InvalidHexLength {
+                expected,
+                actual: digest_chars.len(),
+            });
+        }
+
+        let mut digest = [0_u8; Self::MAX_LENGTH];
+        for (byte, pair) in digest[..kind.digest_len()].iter_mut().zip(digest_chars.chunks(2)) {
+            let hi = pair[0]
+                .to_digit(16)
+                .ok_or(ParseError::InvalidHexCharacter(pair[0]))?;
+            let lo = pair[1]
+                .to_digit(16)
+                .ok_or(ParseError::InvalidHexCharacter(pair[1]))?;
+            *byte = ((hi << 4) | lo) as u8;
+        }
+        let digest = &digest[..kind.digest_len()];
+
+        Self::is_lsb_1(digest)
+            .then(|| Self::with_prehashed_kind(kind, digest))
+            .ok_or(ParseError::LeastSignificantBitNotSet)
     }
 }
 
-impl From<Hash> for [u8; Hash::LENGTH] {
-    #[inline]
-    fn from(hash: Hash) -> Self {
-        #[allow(unsafe_code)]
-        // SAFETY: `Hash` and `[u8; Hash::LENGTH]` have the same memory layout
-        unsafe {
-            core::mem::transmute(hash)
+impl FromStr for Hash {
+    type Err = ParseError;
+
+    fn from_str(hex: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(hex)
+    }
+}
+
+/// Incremental builder for a [`Hash`], obtained from [`Hash::hasher`]/
+/// [`Hash::hasher_with_kind`]. Wraps the chained `Update` pattern the
+/// underlying digests already expose, so a large payload (an encoded block
+/// or transaction) can be fed in chunk by chunk instead of first being
+/// materialized into one contiguous buffer. Mirrors the synchronous
+/// hash-then-finalize pattern used by the Diem/Solana crypto modules.
+#[cfg(feature = "std")]
+pub struct HashBuilder {
+    kind: Kind,
+    inner: HashBuilderInner,
+}
+
+#[cfg(feature = "std")]
+enum HashBuilderInner {
+    Blake2b(VarBlake2b),
+    Sha256(Sha256),
+}
+
+#[cfg(feature = "std")]
+impl HashBuilder {
+    /// Feed another chunk of the payload into the digest.
+    pub fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        match &mut self.inner {
+            HashBuilderInner::Blake2b(hasher) => hasher.update(bytes.as_ref()),
+            HashBuilderInner::Sha256(hasher) => hasher.update(bytes.as_ref()),
+        }
+    }
+
+    /// Finish hashing and produce the [`Hash`].
+    #[must_use]
+    pub fn finalize(self) -> Hash {
+        match self.inner {
+            HashBuilderInner::Blake2b(hasher) => {
+                Hash::with_prehashed_kind(self.kind, &hasher.finalize_boxed())
+            }
+            HashBuilderInner::Sha256(hasher) => {
+                Hash::with_prehashed_kind(self.kind, &hasher.finalize())
+            }
         }
     }
 }
 
-impl AsRef<[u8; Hash::LENGTH]> for Hash {
-    #[inline]
-    fn as_ref(&self) -> &[u8; Hash::LENGTH] {
-        #[allow(unsafe_code, trivial_casts)]
-        // SAFETY: `Hash` and `[u8; Hash::LENGTH]` have the same memory layout
-        unsafe {
-            &*((self as *const Self).cast::<[u8; Self::LENGTH]>())
+/// Error parsing a [`Hash`] or [`HashOf`] from its hexadecimal textual form,
+/// analogous to `gix_hash::decode::Error`.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// The leading [`Kind`] tag does not name a recognised hash algorithm.
+    #[display(fmt = "{_0}")]
+    UnknownKind(UnknownKind),
+    /// The hex string is not exactly as long as the tag plus the tagged
+    /// [`Kind`]'s digest requires.
+    #[display(fmt = "expected {expected} hex characters, got {actual}")]
+    InvalidHexLength {
+        /// Number of hex characters the tagged [`Kind`]'s digest requires
+        /// after the tag, or `2` when the string is too short for the tag.
+        expected: usize,
+        /// Number of hex characters actually given after the tag, or in
+        /// total when the string is too short for the tag.
+        actual: usize,
+    },
+    /// The hex string contains a character that is not a hex digit.
+    #[display(fmt = "{_0:?} is not a valid hex character")]
+    InvalidHexCharacter(char),
+    /// The parsed digest's least significant bit is not set.
+    #[display(fmt = "expected least significant bit of hash to be 1")]
+    LeastSignificantBitNotSet,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// An abbreviated [`Hash`], identified by some number of significant leading
+/// hex nibbles rather than the full digest. Modeled on `gix_hash`'s
+/// `Prefix`, this gives block/transaction explorers Git-style short-hash
+/// resolution: look up a [`HashPrefix`] against a sorted set of full hashes
+/// to detect a unique match or an ambiguous one.
+///
+/// A [`HashPrefix`] is only meaningful against [`Kind::default`] hashes;
+/// unlike [`Hash::from_hex`]/[`HashOf::from_hex`], it carries no [`Kind`] tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HashPrefix {
+    bytes: [u8; Hash::LENGTH],
+    hex_len: usize,
+}
+
+impl HashPrefix {
+    /// Parse a prefix from `1..=2 * Hash::LENGTH` hex nibbles.
+    pub fn from_hex(hex: &str) -> Result<Self, PrefixParseError> {
+        let chars: Vec<char> = hex.chars().collect();
+        if chars.is_empty() {
+            return Err(PrefixParseError::Empty);
+        }
+        if chars.len() > 2 * Hash::LENGTH {
+            return Err(PrefixParseError::TooLong {
+                max: 2 * Hash::LENGTH,
+                actual: chars.len(),
+            });
+        }
+
+        let mut bytes = [0_u8; Hash::LENGTH];
+        for (byte, pair) in bytes.iter_mut().zip(chars.chunks(2)) {
+            let hi = pair[0]
+                .to_digit(16)
+                .ok_or(PrefixParseError::InvalidHexCharacter(pair[0]))?;
+            let lo = match pair.get(1) {
+                Some(&c) => c
+                    .to_digit(16)
+                    .ok_or(PrefixParseError::InvalidHexCharacter(c))?,
+                None => 0,
+            };
+            *byte = ((hi << 4) | lo) as u8;
         }
+
+        Ok(Self {
+            bytes,
+            hex_len: chars.len(),
+        })
+    }
+
+    /// Number of significant hex nibbles this prefix carries.
+    #[must_use]
+    pub const fn hex_len(&self) -> usize {
+        self.hex_len
+    }
+
+    /// Compare this prefix against `hash`, considering only the prefix's
+    /// significant leading nibbles. When `hex_len` is odd the prefix ends
+    /// mid-byte, so the trailing nibble of the last significant byte is
+    /// masked out of the comparison.
+    ///
+    /// A [`HashPrefix`] is only meaningful against [`Kind::default`] hashes
+    /// (see the type-level doc comment), so a `hash` of any other [`Kind`]
+    /// compares unequal by `kind` alone — this is the same `kind`-then-bytes
+    /// order [`Hash`]'s own `Ord` uses, just with the prefix standing in for
+    /// a [`Kind::default`] hash, so comparisons stay consistent with it and
+    /// a sorted slice of hashes can be searched with
+    /// `slice.binary_search_by(|hash| prefix.cmp_hash(hash).reverse())`.
+    #[must_use]
+    pub fn cmp_hash(&self, hash: &Hash) -> core::cmp::Ordering {
+        let kind_ordering = Kind::default().cmp(&hash.kind());
+        if kind_ordering != core::cmp::Ordering::Equal {
+            return kind_ordering;
+        }
+
+        let full_bytes = self.hex_len / 2;
+        let hash_bytes = hash.as_bytes();
+
+        match self.bytes[..full_bytes].cmp(&hash_bytes[..full_bytes]) {
+            core::cmp::Ordering::Equal if self.hex_len % 2 == 1 => {
+                const NIBBLE_MASK: u8 = 0xF0;
+                (self.bytes[full_bytes] & NIBBLE_MASK).cmp(&(hash_bytes[full_bytes] & NIBBLE_MASK))
+            }
+            ordering => ordering,
+        }
+    }
+
+    /// Whether `hash` begins with this prefix.
+    #[must_use]
+    pub fn matches(&self, hash: &Hash) -> bool {
+        self.cmp_hash(hash) == core::cmp::Ordering::Equal
+    }
+}
+
+impl core::fmt::Display for HashPrefix {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&hex::encode(self.bytes)[..self.hex_len])
+    }
+}
+
+impl FromStr for HashPrefix {
+    type Err = PrefixParseError;
+
+    fn from_str(hex: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(hex)
+    }
+}
+
+/// Error parsing a [`HashPrefix`] from its hexadecimal textual form.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum PrefixParseError {
+    /// A prefix must name at least one hex nibble.
+    #[display(fmt = "a hash prefix must contain at least one hex character")]
+    Empty,
+    /// More hex nibbles were given than a full digest has.
+    #[display(fmt = "hash prefix of {actual} hex characters exceeds the maximum of {max}")]
+    TooLong {
+        /// Maximum number of hex characters a prefix may contain.
+        max: usize,
+        /// Number of hex characters actually given.
+        actual: usize,
+    },
+    /// The hex string contains a character that is not a hex digit.
+    #[display(fmt = "{_0:?} is not a valid hex character")]
+    InvalidHexCharacter(char),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PrefixParseError {}
+
+impl AsRef<[u8]> for Hash {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
     }
 }
 
@@ -117,41 +562,92 @@ impl Serialize for Hash {
     where
         S: serde::Serializer,
     {
-        let hash: &[u8; Self::LENGTH] = self.as_ref();
-        hash.serialize(serializer)
+        use serde::ser::SerializeSeq;
+
+        // NOTE: streamed element-by-element rather than collected into a
+        // `Vec` first, so serializing a hash doesn't cost an extra heap
+        // allocation.
+        let digest = self.as_bytes();
+        let mut seq = serializer.serialize_seq(Some(1 + digest.len()))?;
+        seq.serialize_element(&(self.kind as u8))?;
+        for byte in digest {
+            seq.serialize_element(byte)?;
+        }
+        seq.end()
     }
 }
 
 impl<'de> Deserialize<'de> for Hash {
+    /// Deserialize a [`Kind`]-tagged payload, i.e. one produced by this
+    /// crate's own `Serialize` impl.
+    ///
+    /// There is no backend-agnostic way to also accept a legacy, untagged
+    /// `Kind::default()` payload here: it was written through the derived
+    /// fixed-size-array impl, which most non-self-describing formats (e.g.
+    /// `bincode`) encode with no length prefix at all, while this impl reads
+    /// a dynamically sized sequence — on such a format, trying both on the
+    /// same deserializer would desync the reader rather than fail cleanly.
+    /// Deserializing pre-migration data must go through
+    /// [`Hash::deserialize_legacy`] instead, at a call site that knows it is
+    /// reading pre-migration data; this impl only ever understands the
+    /// tagged format and errors otherwise.
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         use serde::de::Error as _;
-        <[u8; Self::LENGTH]>::deserialize(deserializer)
-            .and_then(|hash| {
-                Hash::is_lsb_1(&hash)
-                    .then_some(hash)
-                    .ok_or_else(|| D::Error::custom("expect least significant bit of hash to be 1"))
-            })
-            .map(Self::prehashed)
+
+        let bytes = Vec::<u8>::deserialize(deserializer)?;
+        let (kind_byte, digest) = bytes
+            .split_first()
+            .ok_or_else(|| D::Error::custom("hash payload is empty"))?;
+        let kind = Kind::try_from(*kind_byte).map_err(|e| D::Error::custom(format!("{e}")))?;
+        if digest.len() != kind.digest_len() {
+            return Err(D::Error::custom("hash length does not match its kind"));
+        }
+        Self::is_lsb_1(digest)
+            .then(|| Self::with_prehashed_kind(kind, digest))
+            .ok_or_else(|| D::Error::custom("expect least significant bit of hash to be 1"))
     }
 }
 
 impl Encode for Hash {
-    fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
-        f(self.as_ref())
+    fn size_hint(&self) -> usize {
+        1 + self.as_bytes().len()
+    }
+
+    fn encode_to<O: parity_scale_codec::Output + ?Sized>(&self, dest: &mut O) {
+        // NOTE: two separate writes straight into `dest` rather than one
+        // concatenated into a `Vec` first, so encoding a hash (e.g. while
+        // streaming a larger value through `HashOf::from_encoded`) doesn't
+        // cost an extra heap allocation.
+        dest.push_byte(self.kind as u8);
+        dest.write(self.as_bytes());
     }
 }
 
 impl Decode for Hash {
+    /// Decode a [`Kind`]-tagged payload, i.e. one produced by this crate's
+    /// own `Encode` impl.
+    ///
+    /// There is no way to tell such a payload apart from a legacy, untagged
+    /// `Kind::default()` digest by peeking at its leading byte: that byte is
+    /// itself part of a uniformly random digest, so it equals a valid `Kind`
+    /// discriminant (`0`, `1`, or `2`) by chance about 1.2% of the time,
+    /// which would silently desync the rest of the input stream. Decoding
+    /// pre-migration data must go through [`Hash::decode_legacy`] instead, at
+    /// a call site that knows it is reading pre-migration data; this impl
+    /// only ever understands the tagged format and errors otherwise.
     fn decode<I: parity_scale_codec::Input>(
         input: &mut I,
     ) -> Result<Self, parity_scale_codec::Error> {
-        <[u8; Self::LENGTH]>::decode(input)
-            .and_then(|hash| {
-                Hash::is_lsb_1(&hash)
-                    .then_some(hash)
-                    .ok_or_else(|| "expect least significant bit of hash to be 1".into())
-            })
-            .map(Self::prehashed)
+        let kind_byte = u8::decode(input)?;
+        let kind = Kind::try_from(kind_byte)
+            .map_err(|_| parity_scale_codec::Error::from("unrecognised hash kind discriminant"))?;
+
+        let mut digest = [0_u8; Self::MAX_LENGTH];
+        let digest = &mut digest[..kind.digest_len()];
+        input.read(digest)?;
+        Hash::is_lsb_1(digest)
+            .then(|| Self::with_prehashed_kind(kind, digest))
+            .ok_or_else(|| "expect least significant bit of hash to be 1".into())
     }
 }
 
@@ -161,7 +657,7 @@ impl<T> From<HashOf<T>> for Hash {
     }
 }
 
-/// Represents hash of Iroha entities like `Block` or `Transaction`. Currently supports only blake2b-32.
+/// Represents hash of Iroha entities like `Block` or `Transaction`.
 // Lint triggers when expanding #[codec(skip)]
 #[allow(clippy::default_trait_access)]
 #[derive(DebugCustom, Deref, DerefMut, Display, Decode, Encode, Deserialize, Serialize)]
@@ -207,8 +703,8 @@ impl<T> hash::Hash for HashOf<T> {
     }
 }
 
-impl<T> AsRef<[u8; Hash::LENGTH]> for HashOf<T> {
-    fn as_ref(&self) -> &[u8; Hash::LENGTH] {
+impl<T> AsRef<[u8]> for HashOf<T> {
+    fn as_ref(&self) -> &[u8] {
         self.0.as_ref()
     }
 }
@@ -221,6 +717,20 @@ impl<T> HashOf<T> {
     pub const fn transmute<F>(self) -> HashOf<F> {
         HashOf(self.0, PhantomData)
     }
+
+    /// Parse a typed hash from its hexadecimal textual form, i.e. the
+    /// inverse of [`HashOf`]'s `Display` output.
+    pub fn from_hex(hex: &str) -> Result<Self, ParseError> {
+        Hash::from_hex(hex).map(Hash::typed)
+    }
+}
+
+impl<T> FromStr for HashOf<T> {
+    type Err = ParseError;
+
+    fn from_str(hex: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(hex)
+    }
 }
 
 impl<T: Encode> HashOf<T> {
@@ -228,7 +738,31 @@ impl<T: Encode> HashOf<T> {
     #[cfg(feature = "std")]
     #[must_use]
     pub fn new(value: &T) -> Self {
-        Self(Hash::new(value.encode()), PhantomData)
+        Self::from_encoded(value)
+    }
+
+    /// Construct a typed hash by streaming `value`'s SCALE encoding straight
+    /// into the digest, chunk by chunk, instead of first collecting it into
+    /// one heap-allocated buffer via [`Encode::encode`].
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn from_encoded(value: &T) -> Self {
+        let mut hasher = Hash::hasher();
+        value.encode_to(&mut HashBuilderOutput(&mut hasher));
+        Self(hasher.finalize(), PhantomData)
+    }
+}
+
+/// Adapts [`HashBuilder`] to [`parity_scale_codec::Output`], so
+/// [`Encode::encode_to`] can feed an encoded value's bytes directly into the
+/// digest as they are produced.
+#[cfg(feature = "std")]
+struct HashBuilderOutput<'a>(&'a mut HashBuilder);
+
+#[cfg(feature = "std")]
+impl parity_scale_codec::Output for HashBuilderOutput<'_> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
     }
 }
 
@@ -247,6 +781,66 @@ impl<T: IntoSchema> IntoSchema for HashOf<T> {
     }
 }
 
+/// A [`core::hash::Hasher`] for collections keyed by [`Hash`]/[`HashOf<T>`].
+///
+/// `Hash` is already a uniformly distributed cryptographic digest, so
+/// running it through `SipHash` again for every lookup in a
+/// `HashMap<HashOf<Tx>, _>` is wasted work on a hot path. As the note on
+/// `gix_hash::ObjectId`'s `Hash` impl puts it, a custom hasher for digests
+/// may simply copy a truncated digest instead of mixing it: this one reads
+/// the first 8 bytes of the digest directly as the `u64` state.
+///
+/// Use [`HashMapOfHashes`]/[`HashSetOfHashes`] for large collections of
+/// hashes to cut CPU cost. The existing `core::hash::Hash` impls on
+/// [`Hash`]/[`HashOf`] are left intact, so this is opt-in and generic code
+/// that relies on `SipHash`-quality collision resistance is unaffected.
+#[cfg(feature = "std")]
+#[derive(Default)]
+pub struct HashHasher(u64);
+
+#[cfg(feature = "std")]
+impl core::hash::Hasher for HashHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write_length_prefix(&mut self, _len: usize) {
+        // NOTE: the stdlib `Hash` impl for slices (which `Hash::as_bytes`
+        // goes through) calls this before writing the actual payload bytes.
+        // The length is already implied by `Kind`, so there is no extra
+        // entropy worth folding in here, and skipping it saves a `write`
+        // whose state the digest write below would overwrite anyway.
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        if let Some(first_8) = bytes.get(..8) {
+            let mut state = [0_u8; 8];
+            state.copy_from_slice(first_8);
+            self.0 = u64::from_ne_bytes(state);
+        } else {
+            // NOTE: absorbs odd short writes (e.g. a lone `Kind`
+            // discriminant byte) without discarding the state already held.
+            for &byte in bytes {
+                self.0 = self.0.rotate_left(8) ^ u64::from(byte);
+            }
+        }
+    }
+}
+
+/// [`core::hash::BuildHasher`] for [`HashHasher`].
+#[cfg(feature = "std")]
+pub type HashBuildHasher = core::hash::BuildHasherDefault<HashHasher>;
+
+/// A [`std::collections::HashMap`] keyed by [`Hash`] or [`HashOf<T>`]
+/// digests, using [`HashHasher`] instead of `SipHash`.
+#[cfg(feature = "std")]
+pub type HashMapOfHashes<K, V> = std::collections::HashMap<K, V, HashBuildHasher>;
+
+/// A [`std::collections::HashSet`] of [`Hash`] or [`HashOf<T>`] digests,
+/// using [`HashHasher`] instead of `SipHash`.
+#[cfg(feature = "std")]
+pub type HashSetOfHashes<K> = std::collections::HashSet<K, HashBuildHasher>;
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::restriction)]
@@ -269,4 +863,236 @@ mod tests {
             );
         })
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn default_kind_is_blake2b256() {
+        assert_eq!(Kind::default(), Kind::Blake2b256);
+        let hash = Hash::new("i am data");
+        assert_eq!(hash.kind(), Kind::Blake2b256);
+        assert_eq!(hash.as_bytes().len(), Hash::LENGTH);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn with_kind_produces_matching_digest_length() {
+        let hash = Hash::with_kind(Kind::Blake2b512, "i am data");
+        assert_eq!(hash.kind(), Kind::Blake2b512);
+        assert_eq!(hash.as_bytes().len(), Kind::Blake2b512.digest_len());
+
+        let hash = Hash::with_kind(Kind::Sha256, "i am data");
+        assert_eq!(hash.kind(), Kind::Sha256);
+        assert_eq!(hash.as_bytes().len(), Kind::Sha256.digest_len());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn unknown_kind_byte_is_rejected() {
+        assert_eq!(Kind::try_from(3), Err(UnknownKind(3)));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash_round_trips_through_hex() {
+        let hash = Hash::new("i am data");
+        let parsed: Hash = hash.to_string().parse().unwrap();
+        assert_eq!(hash, parsed);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn non_default_kind_hash_round_trips_through_hex() {
+        // `Kind::Sha256` digests are the same length as `Kind::default`
+        // (`Blake2b256`) ones, so without a tag in the textual form this
+        // would silently parse back as the wrong `Kind`.
+        let hash = Hash::with_kind(Kind::Sha256, "i am data");
+        let parsed: Hash = hash.to_string().parse().unwrap();
+        assert_eq!(hash, parsed);
+        assert_eq!(parsed.kind(), Kind::Sha256);
+
+        let hash = Hash::with_kind(Kind::Blake2b512, "i am data");
+        let parsed: Hash = hash.to_string().parse().unwrap();
+        assert_eq!(hash, parsed);
+        assert_eq!(parsed.kind(), Kind::Blake2b512);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn from_hex_rejects_wrong_length() {
+        // "00" is a valid `Kind::Blake2b256` tag, so the remaining 8 hex
+        // characters are checked against its expected digest length.
+        assert_eq!(
+            Hash::from_hex("00deadbeef"),
+            Err(ParseError::InvalidHexLength {
+                expected: 64,
+                actual: 8
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn from_hex_rejects_unknown_kind_tag() {
+        let hex = format!("ff{}", "0".repeat(64));
+        assert_eq!(Hash::from_hex(&hex), Err(ParseError::UnknownKind(UnknownKind(0xFF))));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn from_hex_rejects_non_hex_character() {
+        let hex = "g".repeat(64);
+        assert_eq!(Hash::from_hex(&hex), Err(ParseError::InvalidHexCharacter('g')));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash_prefix_matches_its_own_hash() {
+        let hash = Hash::new("i am data");
+        // `HashPrefix` abbreviates the significant digest bytes, not
+        // `Hash`'s own tag-prefixed `Display` form.
+        let full_hex = hex::encode(hash.as_bytes());
+
+        for hex_len in [1, 2, 7, 63, 64] {
+            let prefix = HashPrefix::from_hex(&full_hex[..hex_len]).unwrap();
+            assert_eq!(prefix.hex_len(), hex_len);
+            assert!(prefix.matches(&hash), "prefix of length {hex_len} should match");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash_prefix_detects_mismatch() {
+        let hash = Hash::new("i am data");
+        let other = Hash::new("i am other data");
+        assert_ne!(hash, other);
+
+        let prefix = HashPrefix::from_hex(&hex::encode(hash.as_bytes())).unwrap();
+        assert!(!prefix.matches(&other));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash_prefix_never_matches_a_non_default_kind_hash() {
+        // Two distinct `Kind::Blake2b512` digests sharing the same leading
+        // 32 bytes: a "full" 64-nibble prefix built from those 32 bytes
+        // would compare equal to both unless `kind` is checked first, since
+        // it only ever sees `Hash::LENGTH` bytes.
+        let mut first = [0_u8; 64];
+        first[..32].copy_from_slice(b"01234567890123456789012345678901");
+        first[32..].copy_from_slice(b"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa");
+        let mut second = first;
+        second[32..].copy_from_slice(b"bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb");
+
+        let hash = Hash::with_prehashed_kind(Kind::Blake2b512, &first);
+        let other = Hash::with_prehashed_kind(Kind::Blake2b512, &second);
+        assert_ne!(hash, other);
+
+        let prefix = HashPrefix::from_hex(&hex::encode(&first[..Hash::LENGTH])).unwrap();
+        assert!(!prefix.matches(&hash));
+        assert!(!prefix.matches(&other));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash_prefix_rejects_empty_and_overlong() {
+        assert_eq!(HashPrefix::from_hex(""), Err(PrefixParseError::Empty));
+        assert_eq!(
+            HashPrefix::from_hex(&"a".repeat(65)),
+            Err(PrefixParseError::TooLong {
+                max: 64,
+                actual: 65
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash_map_of_hashes_round_trips() {
+        let mut map: HashMapOfHashes<Hash, &str> = HashMapOfHashes::default();
+        let hash = Hash::new("i am data");
+        map.insert(hash, "data");
+        assert_eq!(map.get(&hash), Some(&"data"));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn incremental_hasher_matches_one_shot() {
+        let one_shot = Hash::new("i am data");
+
+        let mut incremental = Hash::hasher();
+        incremental.update("i am ");
+        incremental.update("data");
+
+        assert_eq!(one_shot, incremental.finalize());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn from_encoded_matches_encode_then_hash() {
+        let value: u32 = 0xDEAD_BEEF;
+        let buffered: HashOf<u32> = HashOf(Hash::new(value.encode()), PhantomData);
+        assert_eq!(buffered, HashOf::from_encoded(&value));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash_scale_round_trips() {
+        let hash = Hash::new("i am data");
+        let encoded = hash.encode();
+        let decoded = Hash::decode(&mut encoded.as_slice()).unwrap();
+        assert_eq!(hash, decoded);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn decode_legacy_recovers_an_untagged_pre_kind_digest() {
+        // A payload as it would have been written before `Kind` existed: a
+        // bare 32-byte blake2b digest, with no discriminant byte, encoded
+        // exactly as `[u8; Hash::LENGTH]` SCALE-encodes (i.e. as itself).
+        let digest = {
+            let mut digest = *b"01234567890123456789012345678901";
+            digest[31] |= 1;
+            digest
+        };
+        let legacy_encoded = digest.encode();
+
+        let decoded = Hash::decode_legacy(&mut legacy_encoded.as_slice()).unwrap();
+        assert_eq!(decoded, Hash::prehashed(digest));
+        assert_eq!(decoded.kind(), Kind::Blake2b256);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn decode_rejects_an_unrecognised_kind_discriminant() {
+        let mut encoded = vec![0xFF_u8];
+        encoded.extend_from_slice(&[0_u8; Hash::LENGTH]);
+        assert!(Hash::decode(&mut encoded.as_slice()).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash_serde_round_trips() {
+        let hash = Hash::new("i am data");
+        let value = serde_json::to_value(hash).unwrap();
+        let decoded: Hash = serde_json::from_value(value).unwrap();
+        assert_eq!(hash, decoded);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn deserialize_legacy_recovers_an_untagged_pre_kind_digest() {
+        // A payload as it would have been written before `Kind` existed: a
+        // bare 32-byte blake2b digest, serialized exactly as
+        // `[u8; Hash::LENGTH]` derives, with no discriminant byte.
+        let digest = {
+            let mut digest = *b"01234567890123456789012345678901";
+            digest[31] |= 1;
+            digest
+        };
+        let value = serde_json::to_value(digest).unwrap();
+
+        let decoded = Hash::deserialize_legacy(value).unwrap();
+        assert_eq!(decoded, Hash::prehashed(digest));
+        assert_eq!(decoded.kind(), Kind::Blake2b256);
+    }
 }